@@ -0,0 +1,3 @@
+-- Add migration script here
+-- 予約受付フラグ (FALSE の間は一般ユーザーからの予約を受け付けない)
+ALTER TABLE trips ADD COLUMN bookable BOOLEAN NOT NULL DEFAULT TRUE;
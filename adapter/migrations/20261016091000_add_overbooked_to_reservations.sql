@@ -0,0 +1,3 @@
+-- Add migration script here
+-- 定員を超えて受け付けた予約 (OVERBOOK_PERCENT によるオーバーブッキング分) の印
+ALTER TABLE reservations ADD COLUMN overbooked BOOLEAN NOT NULL DEFAULT FALSE;
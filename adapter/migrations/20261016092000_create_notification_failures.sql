@@ -0,0 +1,12 @@
+-- Add migration script here
+-- 送信に失敗した通知の記録 (管理画面から再送できるようにする)
+CREATE TABLE notification_failures (
+    notification_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    channel TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    error TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'failed', -- 'failed' (未送信) / 'sent' (再送成功)
+    attempts INT NOT NULL DEFAULT 1,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
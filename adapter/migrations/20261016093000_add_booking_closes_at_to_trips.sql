@@ -0,0 +1,3 @@
+-- Add migration script here
+-- 予約締切日時 (NULL の場合は出発時刻で締め切る)
+ALTER TABLE trips ADD COLUMN booking_closes_at TIMESTAMP;
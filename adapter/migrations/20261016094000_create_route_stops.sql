@@ -0,0 +1,21 @@
+-- Add migration script here
+-- ルートの停留所 (始点・途中・終点を stop_order の順に並べる)
+CREATE TABLE route_stops (
+    route_id UUID NOT NULL REFERENCES routes(route_id),
+    bus_stop_id UUID NOT NULL REFERENCES bus_stops(bus_stop_id),
+    stop_order INT NOT NULL,
+    PRIMARY KEY (route_id, stop_order),
+    UNIQUE (route_id, bus_stop_id)
+);
+
+-- 既存ルートの始点・終点を登録
+INSERT INTO route_stops (route_id, bus_stop_id, stop_order)
+SELECT route_id, source_bus_stop_id, 1 FROM routes WHERE source_bus_stop_id IS NOT NULL;
+
+INSERT INTO route_stops (route_id, bus_stop_id, stop_order)
+SELECT route_id, destination_bus_stop_id, 2 FROM routes WHERE destination_bus_stop_id IS NOT NULL;
+
+-- 予約ごとの乗車・降車停留所 (NULL の場合は始点・終点)
+ALTER TABLE reservations
+    ADD COLUMN boarding_stop_id UUID REFERENCES bus_stops(bus_stop_id),
+    ADD COLUMN alighting_stop_id UUID REFERENCES bus_stops(bus_stop_id);
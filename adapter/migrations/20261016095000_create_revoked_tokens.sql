@@ -0,0 +1,8 @@
+-- Add migration script here
+-- ログアウト等で無効化したトークン (JWT の jti) の一覧
+-- expires_at を過ぎたものはトークン自体が期限切れなので定期処理で削除する
+CREATE TABLE revoked_tokens (
+    jti UUID PRIMARY KEY,
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,3 @@
+-- Add migration script here
+-- 予約ごとのメモ (例: 「自転車を持ち込みます」)。乗車名簿に表示して運転手が準備できるようにする
+ALTER TABLE reservations ADD COLUMN notes TEXT;
@@ -0,0 +1,11 @@
+-- Add migration script here
+-- 予約のキャンセルを論理削除にする (キャンセル理由を集計できるように記録を残す)
+ALTER TABLE reservations ADD COLUMN cancelled_at TIMESTAMP;
+ALTER TABLE reservations ADD COLUMN cancellation_reason TEXT
+    CHECK (cancellation_reason IN ('changed_plans', 'weather', 'found_other_ride', 'other'));
+
+-- 一意制約は「有効な予約」だけに掛ける (キャンセル済みの席・便は取り直せるように)
+ALTER TABLE reservations DROP CONSTRAINT reservations_trip_id_seat_number_key;
+ALTER TABLE reservations DROP CONSTRAINT unique_user_per_trip;
+CREATE UNIQUE INDEX reservations_active_seat_key ON reservations (trip_id, seat_number) WHERE cancelled_at IS NULL;
+CREATE UNIQUE INDEX reservations_active_user_key ON reservations (trip_id, user_id) WHERE cancelled_at IS NULL;
@@ -0,0 +1,13 @@
+-- Add migration script here
+-- 監査ログ (誰が・いつ・何をしたか)
+-- なりすまし (impersonation) の開始や、なりすまし中の操作を記録する
+CREATE TABLE audit_logs (
+    audit_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    actor_user_id UUID NOT NULL REFERENCES users(user_id),  -- 操作した本人 (なりすまし中は管理者)
+    action TEXT NOT NULL,
+    target_user_id UUID REFERENCES users(user_id),          -- 操作の対象ユーザー
+    details JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX audit_logs_created_at_idx ON audit_logs (created_at);
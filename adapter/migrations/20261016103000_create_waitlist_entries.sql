@@ -0,0 +1,12 @@
+-- Add migration script here
+-- 満席の便のキャンセル待ち
+-- 席が空いたら created_at の古い順 (先着順) に予約へ繰り上げ、行は削除する
+CREATE TABLE waitlist_entries (
+    waitlist_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    trip_id UUID NOT NULL REFERENCES trips(trip_id),
+    user_id UUID NOT NULL REFERENCES users(user_id),
+    created_at TIMESTAMP NOT NULL DEFAULT clock_timestamp(),
+    UNIQUE (trip_id, user_id)
+);
+
+CREATE INDEX waitlist_entries_trip_order_idx ON waitlist_entries (trip_id, created_at, waitlist_id);
@@ -0,0 +1,3 @@
+-- Add migration script here
+-- 「直近に変わった運行状況」を取得するためのインデックス
+CREATE INDEX operational_statuses_updated_at_idx ON operational_statuses (updated_at);
@@ -0,0 +1,4 @@
+-- Add migration script here
+-- 始点と終点が同じルートは作れないようにする
+ALTER TABLE routes ADD CONSTRAINT routes_distinct_stops_check
+    CHECK (source_bus_stop_id <> destination_bus_stop_id);
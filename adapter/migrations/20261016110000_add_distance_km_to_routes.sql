@@ -0,0 +1,3 @@
+-- Add migration script here
+-- ルートの距離 (km)。CO2削減量の推計に使う。未設定のルートは推計の対象外
+ALTER TABLE routes ADD COLUMN distance_km DOUBLE PRECISION CHECK (distance_km > 0);
@@ -0,0 +1,8 @@
+-- Add migration script here
+-- 最少催行人数と催行判定の日時 (どちらも NULL なら人数に関係なく運行する)
+-- confirm_by の時点で予約数が min_riders に届いていなければ自動で運休にする
+ALTER TABLE trips
+    ADD COLUMN min_riders INTEGER CHECK (min_riders > 0),
+    ADD COLUMN confirm_by TIMESTAMP,
+    ADD COLUMN viability_checked BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD CONSTRAINT trips_min_riders_confirm_by_check CHECK ((min_riders IS NULL) = (confirm_by IS NULL));
@@ -0,0 +1,6 @@
+-- Add migration script here
+-- 便に割り当てられている車両は削除できないようにする (RESTRICT を明示)
+ALTER TABLE trips DROP CONSTRAINT trips_vehicle_id_fkey;
+ALTER TABLE trips
+    ADD CONSTRAINT trips_vehicle_id_fkey
+    FOREIGN KEY (vehicle_id) REFERENCES vehicles(vehicle_id) ON DELETE RESTRICT;
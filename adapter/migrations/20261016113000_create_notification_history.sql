@@ -0,0 +1,12 @@
+-- Add migration script here
+-- 利用者ごとの通知履歴 (送信できた通知を宛先ごとに1行ずつ残す)
+CREATE TABLE notification_history (
+    notification_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(user_id),
+    channel TEXT NOT NULL,  -- teams / slack
+    subject TEXT NOT NULL,
+    body TEXT NOT NULL,
+    sent_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX notification_history_user_id_sent_at_idx ON notification_history (user_id, sent_at DESC);
@@ -0,0 +1,7 @@
+-- Add migration script here
+-- 車種ごとの座席レイアウト
+-- seat_columns: 1列の席数 (NULL なら1席ずつ縦に並べる)
+-- aisle_after_columns: 通路がある位置 (例: {2} なら2席目と3席目の間が通路)
+ALTER TABLE vehicle_types
+    ADD COLUMN seat_columns INTEGER CHECK (seat_columns > 0),
+    ADD COLUMN aisle_after_columns INTEGER[] NOT NULL DEFAULT '{}';
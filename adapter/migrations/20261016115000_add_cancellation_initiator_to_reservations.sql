@@ -0,0 +1,9 @@
+-- Add migration script here
+-- 誰がキャンセルしたか (user: 利用者自身 / operator: 運休・管理者による取り消し)
+-- 運営側の都合でのキャンセルは返金の対象になるので区別しておく
+ALTER TABLE reservations
+    ADD COLUMN cancellation_initiator TEXT CHECK (cancellation_initiator IN ('user', 'operator'));
+
+-- 既存データ: 理由が付いているものは利用者のキャンセル (理由は利用者しか付けない)
+UPDATE reservations SET cancellation_initiator = 'user'
+WHERE cancelled_at IS NOT NULL AND cancellation_reason IS NOT NULL;
@@ -0,0 +1,21 @@
+-- Add migration script here
+-- 定期予約 (通勤便などを、ルートと曜日を指定して自動で予約する)
+-- weekdays は ISO の曜日番号 (1 = 月曜 … 7 = 日曜)
+CREATE TABLE recurring_reservations (
+    recurring_reservation_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(user_id),
+    route_id UUID NOT NULL REFERENCES routes(route_id),
+    weekdays INTEGER[] NOT NULL CHECK (cardinality(weekdays) > 0 AND weekdays <@ ARRAY[1, 2, 3, 4, 5, 6, 7]),
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, route_id)
+);
+
+-- 定期予約で処理済みの便
+-- 同じ便を二度処理しない (利用者が自動予約をキャンセルしても取り直さない) ために残す
+CREATE TABLE recurring_reservation_runs (
+    recurring_reservation_id UUID NOT NULL REFERENCES recurring_reservations(recurring_reservation_id) ON DELETE CASCADE,
+    trip_id UUID NOT NULL REFERENCES trips(trip_id),
+    result TEXT NOT NULL CHECK (result IN ('reserved', 'already_reserved', 'full')),
+    processed_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (recurring_reservation_id, trip_id)
+);
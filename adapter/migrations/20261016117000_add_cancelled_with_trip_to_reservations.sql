@@ -0,0 +1,6 @@
+-- Add migration script here
+-- 便の運休によって取り消された予約か
+-- cancellation_initiator = 'operator' には管理者による削除や便の統合も含まれるので、
+-- 運行を再開したときに元に戻す対象 (運休による取り消しだけ) を区別しておく
+ALTER TABLE reservations
+    ADD COLUMN cancelled_with_trip BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,9 @@
+-- Add migration script here
+-- メールアドレスの確認 (登録時に送る確認リンクを開いたら TRUE)
+-- 既存のユーザーは確認済みとして扱い、これから登録するユーザーだけ未確認から始める
+ALTER TABLE users
+    ADD COLUMN email_verified BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN email_verification_sent_at TIMESTAMP;
+
+ALTER TABLE users
+    ALTER COLUMN email_verified SET DEFAULT FALSE;
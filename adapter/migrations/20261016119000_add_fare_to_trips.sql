@@ -0,0 +1,6 @@
+-- Add migration script here
+-- 便の運賃 (表示用。支払いはまだ扱わない)
+-- base_fare は通貨の最小単位 (円なら円) で持つ。未設定 (NULL) なら運賃は表示しない
+ALTER TABLE trips
+    ADD COLUMN base_fare INTEGER CHECK (base_fare >= 0),
+    ADD COLUMN fare_currency TEXT NOT NULL DEFAULT 'JPY' CHECK (fare_currency ~ '^[A-Z]{3}$');
@@ -0,0 +1,6 @@
+-- Add migration script here
+-- 停留所の位置 (路線図の描画用、世界測地系の緯度・経度)
+-- 未登録の停留所は NULL のまま (地図には描かない)
+ALTER TABLE bus_stops
+    ADD COLUMN latitude DOUBLE PRECISION CHECK (latitude BETWEEN -90 AND 90),
+    ADD COLUMN longitude DOUBLE PRECISION CHECK (longitude BETWEEN -180 AND 180);
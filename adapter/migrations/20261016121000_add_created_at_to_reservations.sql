@@ -0,0 +1,8 @@
+-- Add migration script here
+-- 予約した日時 (予約数の推移の集計に使う)
+-- 既存の予約は予約日時が分からないので NULL のままにする (マイグレーションの時刻で埋めると集計が偏る)
+ALTER TABLE reservations ADD COLUMN created_at TIMESTAMP;
+ALTER TABLE reservations ALTER COLUMN created_at SET DEFAULT NOW();
+
+CREATE INDEX reservations_created_at_idx ON reservations (created_at);
+CREATE INDEX reservations_cancelled_at_idx ON reservations (cancelled_at);
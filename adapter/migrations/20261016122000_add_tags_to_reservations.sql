@@ -0,0 +1,5 @@
+-- Add migration script here
+-- 予約のタグ (乗車名簿で配慮が必要な乗客を目立たせる・絞り込むため)
+-- 使える値は決まった一覧だけにする (アプリ側の ReservationTag と同じ値)
+ALTER TABLE reservations ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}'
+    CHECK (tags <@ ARRAY['vip', 'wheelchair', 'assistance_needed']::TEXT[]);
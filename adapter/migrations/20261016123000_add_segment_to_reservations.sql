@@ -0,0 +1,25 @@
+-- Add migration script here
+-- 予約の乗車区間 (乗車停留所〜降車停留所の stop_order の範囲 [乗車, 降車))
+-- 同じ座席でも区間が重ならなければ別の人が予約できるようにする (A→B の人が降りた後の席を B→C の人に売る)
+-- 乗車・降車停留所を指定しない側は上限・下限なし (始点から / 終点まで) とする
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+ALTER TABLE reservations ADD COLUMN segment INT4RANGE NOT NULL DEFAULT '(,)'
+    CHECK (NOT isempty(segment));
+
+-- 既存の予約の区間を、便のルートの停留所の並びから設定する
+UPDATE reservations r
+SET segment = int4range(
+    (SELECT rs.stop_order FROM trips t JOIN route_stops rs ON rs.route_id = t.route_id
+     WHERE t.trip_id = r.trip_id AND rs.bus_stop_id = r.boarding_stop_id),
+    (SELECT rs.stop_order FROM trips t JOIN route_stops rs ON rs.route_id = t.route_id
+     WHERE t.trip_id = r.trip_id AND rs.bus_stop_id = r.alighting_stop_id)
+)
+WHERE r.boarding_stop_id IS NOT NULL OR r.alighting_stop_id IS NOT NULL;
+
+-- 同じ便・同じ座席の有効な予約は、区間が重ならないこと
+-- (座席ごとの一意インデックスの代わり。区間を指定しない予約どうしは今まで通り同じ座席を取れない)
+DROP INDEX reservations_active_seat_key;
+ALTER TABLE reservations ADD CONSTRAINT reservations_active_seat_excl
+    EXCLUDE USING gist (trip_id WITH =, seat_number WITH =, segment WITH &&)
+    WHERE (cancelled_at IS NULL);
@@ -64,7 +64,7 @@ const loadData = async () => {
 
     try {
     const res = await fetch("http://localhost:8000/trips");
-    if (res.ok) setTrips(await res.json());
+    if (res.ok) setTrips((await res.json()).items);
     } catch (e) { console.error(e); }
 };
 
@@ -79,7 +79,7 @@ const [isMaintenance, setIsMaintenance] = useState(false);
 
 // 初回ロード時に今の状態を取得
 useEffect(() => {
-  fetch("http://localhost:8000/admin/maintenance")
+  fetch("http://localhost:8000/maintenance")
     .then(res => res.json())
     .then(data => setIsMaintenance(data))
     .catch(console.error);
@@ -97,8 +97,11 @@ const toggleMaintenance = async () => {
     try {
         const res = await fetch("http://localhost:8000/admin/maintenance", {
         method: "POST",
-        headers: { "Content-Type": "application/json" },
-        body: JSON.stringify({ enabled: newState, user_id: user.user_id }),
+        headers: {
+          "Content-Type": "application/json",
+          Authorization: `Bearer ${user.token}`,
+        },
+        body: JSON.stringify({ enabled: newState }),
         });
         if (res.ok) {
         setIsMaintenance(newState);
@@ -159,9 +162,11 @@ const handleApply = async () => {
     try {
     const res = await fetch("http://localhost:8000/admin/status", {
         method: "POST",
-        headers: { "Content-Type": "application/json" },
+        headers: {
+        "Content-Type": "application/json",
+        Authorization: `Bearer ${user.token}`,
+        },
         body: JSON.stringify({
-        user_id: user.user_id,
         trip_id: trip.trip_id,
         status: status,
         description: description,
@@ -275,9 +280,7 @@ const [arrivalTime, setArrivalTime] = useState("");
 useEffect(() => {
     if (isOpen && user) {
     fetch("http://localhost:8000/admin/options", {
-        method: "POST",
-        headers: { "Content-Type": "application/json" },
-        body: JSON.stringify({ user_id: user.user_id }),
+        headers: { Authorization: `Bearer ${user.token}` },
     })
         .then((res) => res.json())
         .then((data) => setOptions(data))
@@ -294,14 +297,16 @@ const handleSubmit = async () => {
     try {
     const res = await fetch("http://localhost:8000/admin/trips", {
         method: "POST",
-        headers: { "Content-Type": "application/json" },
+        headers: {
+        "Content-Type": "application/json",
+        Authorization: `Bearer ${user.token}`,
+        },
         body: JSON.stringify({
-        user_id: user.user_id,
         route_id: routeId,
         vehicle_id: vehicleId,
         driver_id: driverId,
-        departure_datetime: departureTime + ":00",
-        arrival_datetime: arrivalTime + ":00",
+        departure_datetime: new Date(departureTime).toISOString(),
+        arrival_datetime: new Date(arrivalTime).toISOString(),
         }),
     });
 
@@ -41,10 +41,12 @@ const handleCancel = async (reservationId: string) => {
     try {
     const res = await fetch("http://localhost:8000/reservations/cancel", {
         method: "POST",
-        headers: { "Content-Type": "application/json" },
+        headers: {
+        "Content-Type": "application/json",
+        Authorization: `Bearer ${user.token}`,
+        },
         body: JSON.stringify({
         reservation_id: reservationId,
-        user_id: user.user_id,
         }),
     });
 
@@ -77,13 +79,12 @@ useEffect(() => {
     try {
         const res = await fetch("http://localhost:8000/my-reservations", {
         method: "POST",
-        headers: { "Content-Type": "application/json" },
-        body: JSON.stringify({ user_id: user.user_id }),
+        headers: { Authorization: `Bearer ${user.token}` },
         });
 
         if (res.ok) {
         const data = await res.json();
-        setReservations(data);
+        setReservations(data.items);
         }
     } catch (error) {
         console.error(error);
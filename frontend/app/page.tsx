@@ -44,10 +44,12 @@ const formatDate = (dateString: string) => {
     try {
       const res = await fetch("http://localhost:8000/reservations", {
         method: "POST",
-        headers: { "Content-Type": "application/json" },
+        headers: {
+          "Content-Type": "application/json",
+          Authorization: `Bearer ${user.token}`,
+        },
         body: JSON.stringify({
           trip_id: tripId,
-          user_id: user.user_id,
         }),
       });
 
@@ -76,7 +78,7 @@ export default function Home() {
   const [showPast, setShowPast] = useState(false); // 過去便の表示スイッチ
 
   useEffect(() => {
-    fetch("http://localhost:8000/admin/maintenance")
+    fetch("http://localhost:8000/maintenance")
       .then((res) => res.json())
       .then((data) => setIsMaintenance(data))
       .catch((e) => {
@@ -103,7 +105,7 @@ export default function Home() {
         const res = await fetch("http://localhost:8000/trips");
         if (res.ok) {
           const data = await res.json();
-          setTrips(data);
+          setTrips(data.items);
         } else {
           console.error("データの取得に失敗しました");
         }
@@ -180,7 +182,17 @@ return (
                 <Button variant="secondary" asChild className="mr-2">
                   <Link href="/mypage">予約確認</Link>
                 </Button>
-                <Button variant="outline" onClick={() => {
+                <Button variant="outline" onClick={async () => {
+                  const user = JSON.parse(localStorage.getItem("currentUser") || "{}");
+                  // サーバー側でもトークンを無効化する (失敗してもローカルのログアウトは行う)
+                  await fetch("http://localhost:8000/auth/logout", {
+                    method: "POST",
+                    headers: {
+                      "Content-Type": "application/json",
+                      Authorization: `Bearer ${user.token}`,
+                    },
+                    body: JSON.stringify({ refresh_token: user.refresh_token }),
+                  }).catch(() => {});
                   localStorage.removeItem("currentUser");
                   window.location.reload();
                 }}>ログアウト</Button>
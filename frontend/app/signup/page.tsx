@@ -33,8 +33,7 @@ export default function SignUpPage(){
                 body: JSON.stringify({
                     name: name,
                     email: email,
-                    password: password,
-                    role: "student"
+                    password: password
                 })
             })
             if (response.ok) {
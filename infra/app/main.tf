@@ -40,9 +40,16 @@ resource "aws_apprunner_service" "backend_book_manager" {
       image_configuration {
         port = "8080"
         runtime_environment_variables = {
-          AUTH_TOKEN_TTL = 86400
-          HOST           = "0.0.0.0"
-          PORT           = 8080
+          APP_ENV              = "production"
+          JWT_ISSUER           = "sangi-bus"
+          JWT_AUDIENCE         = "sangi-bus-app"
+          JWT_ACCESS_TTL_SECS  = 86400
+          JWT_REFRESH_TTL_SECS = 2592000
+          CORS_ALLOWED_ORIGINS = join(",", var.cors_allowed_origins)
+          # App Runner はロードバランサー経由で届くので、接続元IPは X-Forwarded-For から取る
+          TRUST_X_FORWARDED_FOR = "true"
+          HOST                  = "0.0.0.0"
+          PORT                  = 8080
         }
         runtime_environment_secrets = {
           DATABASE_HOST     = "${var.book_app_secrets_manager_arn}:DATABASE_HOST::"
@@ -52,6 +59,7 @@ resource "aws_apprunner_service" "backend_book_manager" {
           DATABASE_USERNAME = "${var.book_app_secrets_manager_arn}:DATABASE_USERNAME::"
           REDIS_HOST        = "${var.book_app_secrets_manager_arn}:REDIS_HOST::"
           REDIS_PORT        = "${var.book_app_secrets_manager_arn}:REDIS_PORT::"
+          JWT_SECRET        = "${var.book_app_secrets_manager_arn}:JWT_SECRET::"
         }
       }
       image_identifier      = "${aws_ecr_repository.backend_repository.repository_url}:latest"
@@ -22,3 +22,7 @@ variable "apprunner_ecr_access_role_arn" {
   type = string
 }
 
+
+variable "cors_allowed_origins" {
+  type = list(string)
+}
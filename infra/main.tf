@@ -8,6 +8,12 @@ variable "aws_profile" {
   default = "oxidized-crab"
 }
 
+# フロントエンドを配信するオリジン (例: ["https://main.xxxx.amplifyapp.com"])
+# バックエンドはこのオリジンからのブラウザの呼び出しだけを許可する
+variable "cors_allowed_origins" {
+  type = list(string)
+}
+
 terraform {
   required_version = "~> 1"
 
@@ -60,6 +66,7 @@ module "app" {
   book_app_secrets_manager_arn  = module.secrets.book_app_secrets_manager_arn
   apprunner_instance_role_arn   = module.iam.apprunner_instance_role_arn
   apprunner_ecr_access_role_arn = module.iam.apprunner_ecr_access_role_arn
+  cors_allowed_origins          = var.cors_allowed_origins
 }
 
 module "iam" {
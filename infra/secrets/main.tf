@@ -7,6 +7,7 @@ locals {
     DATABASE_PASSWORD = "fill_your_db_password"
     REDIS_HOST        = "fill_your_redist_host"
     REDIS_PORT        = 6379
+    JWT_SECRET        = "fill_your_jwt_secret"
   }
 }
 